@@ -0,0 +1,289 @@
+use std::str::FromStr;
+
+use super::lch;
+
+/// One of the 8 basic colors.
+///
+/// Each one can be made "light" or "dark" (see [`Color::Dark`] and [`Color::Light`]).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BaseColor {
+    /// Black color
+    Black,
+
+    /// Red color
+    Red,
+
+    /// Green color
+    Green,
+
+    /// Yellow color
+    Yellow,
+
+    /// Blue color
+    Blue,
+
+    /// Magenta color
+    Magenta,
+
+    /// Cyan color
+    Cyan,
+
+    /// White color
+    White,
+}
+
+impl BaseColor {
+    /// Approximate 24-bit RGB value for the dark variant of this color.
+    fn dark_rgb(self) -> (u8, u8, u8) {
+        match self {
+            BaseColor::Black => (0, 0, 0),
+            BaseColor::Red => (128, 0, 0),
+            BaseColor::Green => (0, 128, 0),
+            BaseColor::Yellow => (128, 128, 0),
+            BaseColor::Blue => (0, 0, 128),
+            BaseColor::Magenta => (128, 0, 128),
+            BaseColor::Cyan => (0, 128, 128),
+            BaseColor::White => (192, 192, 192),
+        }
+    }
+
+    /// Approximate 24-bit RGB value for the light variant of this color.
+    fn light_rgb(self) -> (u8, u8, u8) {
+        match self {
+            BaseColor::Black => (128, 128, 128),
+            BaseColor::Red => (255, 0, 0),
+            BaseColor::Green => (0, 255, 0),
+            BaseColor::Yellow => (255, 255, 0),
+            BaseColor::Blue => (0, 0, 255),
+            BaseColor::Magenta => (255, 0, 255),
+            BaseColor::Cyan => (0, 255, 255),
+            BaseColor::White => (255, 255, 255),
+        }
+    }
+}
+
+impl FromStr for BaseColor {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "black" => BaseColor::Black,
+            "red" => BaseColor::Red,
+            "green" => BaseColor::Green,
+            "yellow" => BaseColor::Yellow,
+            "blue" => BaseColor::Blue,
+            "magenta" => BaseColor::Magenta,
+            "cyan" => BaseColor::Cyan,
+            "white" => BaseColor::White,
+            _ => return Err(()),
+        })
+    }
+}
+
+/// Represents a color used by the theme.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Color {
+    /// Indicates that the color should be left as-is, controlled by the terminal.
+    TerminalDefault,
+
+    /// One of the 8 base colors, in its dark variant.
+    Dark(BaseColor),
+
+    /// One of the 8 base colors, in its light variant.
+    Light(BaseColor),
+
+    /// True-color, 24-bit RGB value.
+    Rgb(u8, u8, u8),
+
+    /// Low-resolution color from the 6x6x6 color cube.
+    ///
+    /// Each component must be `<= 5`.
+    RgbLowRes(u8, u8, u8),
+}
+
+impl Color {
+    /// Parses a color description string.
+    ///
+    /// Accepts:
+    /// * `#rrggbb` hex triplets
+    /// * `rgb(r,g,b)` decimal triplets
+    /// * one of the 8 base color names, optionally prefixed with `light` or
+    ///   `dark` (e.g. `"red"`, `"light red"`, `"dark red"`; a bare name
+    ///   without a prefix is treated as `dark`)
+    /// * `"default"` or `"terminal"` for [`Color::TerminalDefault`]
+    ///
+    /// Returns `None` if the string doesn't match any known format. Note
+    /// that palette role names (like `"primary"`) are not colors and are
+    /// not handled here; see `ColorType`'s `Deserialize` implementation.
+    pub fn parse(s: &str) -> Option<Color> {
+        let s = s.trim();
+
+        if let Some(hex) = s.strip_prefix('#') {
+            return Color::parse_hex(hex);
+        }
+
+        if let Some(inner) = s.strip_prefix("rgb(").and_then(|s| s.strip_suffix(')')) {
+            return Color::parse_rgb_triplet(inner);
+        }
+
+        let lower = s.to_ascii_lowercase();
+        match lower.as_str() {
+            "default" | "terminal" => return Some(Color::TerminalDefault),
+            _ => (),
+        }
+
+        if let Some(name) = lower.strip_prefix("light ") {
+            return BaseColor::from_str(name).ok().map(Color::Light);
+        }
+
+        if let Some(name) = lower.strip_prefix("dark ") {
+            return BaseColor::from_str(name).ok().map(Color::Dark);
+        }
+
+        BaseColor::from_str(&lower).ok().map(Color::Dark)
+    }
+
+    fn parse_hex(hex: &str) -> Option<Color> {
+        if hex.len() != 6 || !hex.is_ascii() {
+            return None;
+        }
+
+        let r = u8::from_str_radix(&hex[0..2], 16).ok()?;
+        let g = u8::from_str_radix(&hex[2..4], 16).ok()?;
+        let b = u8::from_str_radix(&hex[4..6], 16).ok()?;
+
+        Some(Color::Rgb(r, g, b))
+    }
+
+    fn parse_rgb_triplet(inner: &str) -> Option<Color> {
+        let mut parts = inner.split(',').map(|part| part.trim().parse::<u8>());
+
+        let r = parts.next()?.ok()?;
+        let g = parts.next()?.ok()?;
+        let b = parts.next()?.ok()?;
+
+        if parts.next().is_some() {
+            return None;
+        }
+
+        Some(Color::Rgb(r, g, b))
+    }
+}
+
+impl FromStr for Color {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Color::parse(s).ok_or(())
+    }
+}
+
+impl Color {
+    /// Returns the 24-bit RGB value this color represents, if any.
+    ///
+    /// Returns `None` for [`Color::TerminalDefault`], which has no concrete
+    /// color to manipulate.
+    fn as_rgb(self) -> Option<(u8, u8, u8)> {
+        match self {
+            Color::TerminalDefault => None,
+            Color::Dark(base) => Some(base.dark_rgb()),
+            Color::Light(base) => Some(base.light_rgb()),
+            Color::Rgb(r, g, b) => Some((r, g, b)),
+            Color::RgbLowRes(r, g, b) => Some((r * 51, g * 51, b * 51)),
+        }
+    }
+
+    /// Applies `f` to this color's Lch(ab) representation and converts the
+    /// result back to a [`Color::Rgb`].
+    ///
+    /// Colors with no concrete RGB value (like [`Color::TerminalDefault`])
+    /// are returned unchanged.
+    fn map_lch<F>(self, f: F) -> Color
+    where
+        F: FnOnce(f32, f32, f32) -> (f32, f32, f32),
+    {
+        let Some((r, g, b)) = self.as_rgb() else {
+            return self;
+        };
+
+        let (l, c, h) = lch::rgb_to_lch(r, g, b);
+        let (l, c, h) = f(l, c, h);
+        let (r, g, b) = lch::lch_to_rgb(l, c, h);
+
+        Color::Rgb(r, g, b)
+    }
+
+    /// Lightens this color by shifting its Lch lightness towards white.
+    ///
+    /// `amount` is a fraction of the full `0..=100` lightness range, so
+    /// `0.1` raises lightness by 10 points; the result is clamped to stay
+    /// in range.
+    #[must_use]
+    pub fn lighten(self, amount: f32) -> Color {
+        self.map_lch(|l, c, h| ((l + amount * 100.0).clamp(0.0, 100.0), c, h))
+    }
+
+    /// Darkens this color by shifting its Lch lightness towards black.
+    ///
+    /// Equivalent to `self.lighten(-amount)`.
+    #[must_use]
+    pub fn darken(self, amount: f32) -> Color {
+        self.lighten(-amount)
+    }
+
+    /// Increases this color's saturation by scaling its Lch chroma.
+    ///
+    /// `amount` is a fraction by which chroma is scaled up (e.g. `0.2` scales
+    /// chroma by `1.2`); the result is clamped to stay non-negative.
+    #[must_use]
+    pub fn saturate(self, amount: f32) -> Color {
+        self.map_lch(|l, c, h| (l, (c * (1.0 + amount)).max(0.0), h))
+    }
+
+    /// Decreases this color's saturation by scaling its Lch chroma.
+    ///
+    /// Equivalent to `self.saturate(-amount)`.
+    #[must_use]
+    pub fn desaturate(self, amount: f32) -> Color {
+        self.saturate(-amount)
+    }
+
+    /// Rotates this color's Lch hue by the given number of degrees.
+    #[must_use]
+    pub fn shift_hue(self, degrees: f32) -> Color {
+        self.map_lch(|l, c, h| (l, c, (h + degrees).rem_euclid(360.0)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_hex() {
+        assert_eq!(Color::parse("#1a1a2e"), Some(Color::Rgb(0x1a, 0x1a, 0x2e)));
+        assert_eq!(Color::parse("#FF0000"), Some(Color::Rgb(255, 0, 0)));
+        assert_eq!(Color::parse("#12345"), None);
+    }
+
+    #[test]
+    fn parses_rgb_triplet() {
+        assert_eq!(Color::parse("rgb(10, 20, 30)"), Some(Color::Rgb(10, 20, 30)));
+        assert_eq!(Color::parse("rgb(300,0,0)"), None);
+    }
+
+    #[test]
+    fn parses_named_colors() {
+        assert_eq!(Color::parse("red"), Some(Color::Dark(BaseColor::Red)));
+        assert_eq!(Color::parse("light red"), Some(Color::Light(BaseColor::Red)));
+        assert_eq!(Color::parse("dark red"), Some(Color::Dark(BaseColor::Red)));
+        assert_eq!(Color::parse("Light Blue"), Some(Color::Light(BaseColor::Blue)));
+        assert_eq!(Color::parse("not-a-color"), None);
+    }
+
+    #[test]
+    fn parses_terminal_default() {
+        assert_eq!(Color::parse("default"), Some(Color::TerminalDefault));
+        assert_eq!(Color::parse("terminal"), Some(Color::TerminalDefault));
+    }
+}
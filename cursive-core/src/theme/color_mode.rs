@@ -0,0 +1,108 @@
+/// Controls whether colors should be used when rendering.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub enum ColorMode {
+    /// Always use color, regardless of the environment or the output terminal.
+    Always,
+
+    /// Never use color; every role resolves to [`Color::TerminalDefault`](super::Color::TerminalDefault).
+    Never,
+
+    /// Use color unless the environment or the output terminal says otherwise.
+    ///
+    /// Falls back to [`ColorMode::Never`] when the output isn't an
+    /// interactive terminal.
+    #[default]
+    Auto,
+}
+
+impl ColorMode {
+    /// Reads color-related environment variables, if any apply.
+    ///
+    /// Priority, highest first:
+    /// * `CLICOLOR_FORCE` (set to anything but `"0"`) forces color on, even
+    ///   on a non-interactive output.
+    /// * `NO_COLOR` (set to anything) disables color.
+    /// * `CLICOLOR` set to `"0"` disables color.
+    ///
+    /// Returns `None` if no relevant variable is set, leaving the caller's
+    /// own mode in effect.
+    pub fn from_env() -> Option<ColorMode> {
+        use std::env::var_os;
+
+        if var_os("CLICOLOR_FORCE").is_some_and(|value| value != "0") {
+            return Some(ColorMode::Always);
+        }
+
+        if var_os("NO_COLOR").is_some() {
+            return Some(ColorMode::Never);
+        }
+
+        if var_os("CLICOLOR").is_some_and(|value| value == "0") {
+            return Some(ColorMode::Never);
+        }
+
+        None
+    }
+
+    /// Resolves this mode to a plain enabled/disabled flag.
+    ///
+    /// `is_tty` should reflect whether the backend's output is an
+    /// interactive terminal. An explicit [`ColorMode::Always`] or
+    /// [`ColorMode::Never`] always wins; environment variables (see
+    /// [`ColorMode::from_env`]) only take over for [`ColorMode::Auto`], and
+    /// `is_tty` is only consulted once neither applies.
+    pub fn effective(self, is_tty: bool) -> bool {
+        match self {
+            ColorMode::Always => true,
+            ColorMode::Never => false,
+            ColorMode::Auto => match ColorMode::from_env() {
+                Some(mode) => mode.effective(is_tty),
+                None => is_tty,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_is_auto() {
+        assert_eq!(ColorMode::default(), ColorMode::Auto);
+    }
+
+    #[test]
+    fn explicit_mode_wins_regardless_of_tty() {
+        assert!(ColorMode::Always.effective(false));
+        assert!(!ColorMode::Never.effective(true));
+    }
+
+    // These all touch process-wide environment variables, so they're merged
+    // into a single test: running them as separate `#[test]`s would let the
+    // test harness interleave them on different threads and race each other.
+    #[test]
+    fn from_env_and_auto_precedence() {
+        std::env::remove_var("CLICOLOR_FORCE");
+        std::env::remove_var("NO_COLOR");
+        std::env::remove_var("CLICOLOR");
+        assert_eq!(ColorMode::from_env(), None);
+        assert!(ColorMode::Auto.effective(true));
+        assert!(!ColorMode::Auto.effective(false));
+
+        std::env::set_var("CLICOLOR", "0");
+        assert_eq!(ColorMode::from_env(), Some(ColorMode::Never));
+        assert!(!ColorMode::Auto.effective(true));
+        std::env::remove_var("CLICOLOR");
+
+        std::env::set_var("NO_COLOR", "1");
+        assert_eq!(ColorMode::from_env(), Some(ColorMode::Never));
+        assert!(!ColorMode::Auto.effective(true));
+        std::env::remove_var("NO_COLOR");
+
+        std::env::set_var("CLICOLOR_FORCE", "1");
+        assert_eq!(ColorMode::from_env(), Some(ColorMode::Always));
+        assert!(ColorMode::Auto.effective(false));
+        std::env::remove_var("CLICOLOR_FORCE");
+    }
+}
@@ -1,3 +1,8 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serde::de::{Deserialize, Deserializer, Visitor};
+
 use super::{BaseColor, Color, ColorPair, Palette, PaletteColor};
 
 /// Possible color style for a cell.
@@ -168,7 +173,7 @@ where
 /// Either a color from the palette, or a direct color.
 ///
 /// The `Default` implementation returns `InheritParent`.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
 pub enum ColorType {
     /// Uses a color from the application palette.
     Palette(PaletteColor),
@@ -177,18 +182,21 @@ pub enum ColorType {
     Color(Color),
 
     /// Re-use the color from the parent.
+    #[default]
     InheritParent,
 }
 
-impl Default for ColorType {
-    fn default() -> Self {
-        ColorType::InheritParent
-    }
-}
-
 impl ColorType {
     /// Given a palette, resolve `self` to a concrete color.
+    ///
+    /// If the palette is currently monochrome (see
+    /// [`Palette::set_color_mode`]), this always returns
+    /// [`Color::TerminalDefault`], regardless of `self`.
     pub fn resolve(self, palette: &Palette, previous: Color) -> Color {
+        if palette.is_monochrome() {
+            return Color::TerminalDefault;
+        }
+
         match self {
             ColorType::Color(color) => color,
             ColorType::Palette(color) => color.resolve(palette),
@@ -219,3 +227,47 @@ impl From<PaletteColor> for ColorType {
         ColorType::Palette(color)
     }
 }
+
+impl FromStr for ColorType {
+    type Err = ();
+
+    /// Parses a color string into a `ColorType`.
+    ///
+    /// Tries a concrete [`Color`] first (hex, `rgb(...)`, named base colors,
+    /// `default`/`terminal`), then falls back to a palette role name (e.g.
+    /// `"primary"`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(color) = Color::parse(s) {
+            return Ok(ColorType::Color(color));
+        }
+
+        PaletteColor::from_str(s).map(ColorType::Palette)
+    }
+}
+
+impl<'de> Deserialize<'de> for ColorType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ColorTypeVisitor;
+
+        impl Visitor<'_> for ColorTypeVisitor {
+            type Value = ColorType;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a color (hex, rgb(...), a base color name, or a palette role name)")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                ColorType::from_str(value)
+                    .map_err(|_| E::custom(format!("invalid color or palette role `{}`", value)))
+            }
+        }
+
+        deserializer.deserialize_str(ColorTypeVisitor)
+    }
+}
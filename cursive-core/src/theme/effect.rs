@@ -0,0 +1,32 @@
+use enumset::EnumSetType;
+
+/// A text effect that can be layered on top of a [`ColorStyle`](super::ColorStyle).
+///
+/// Effects are usually combined into an [`EnumSet<Effect>`](enumset::EnumSet)
+/// and applied together through a [`Style`](super::Style).
+#[derive(EnumSetType, Debug)]
+pub enum Effect {
+    /// Bold text
+    Bold,
+
+    /// Italic text
+    Italic,
+
+    /// Underlined text
+    Underline,
+
+    /// Strikethrough text
+    Strikethrough,
+
+    /// Dimmed text
+    Dim,
+
+    /// Blinking text
+    Blink,
+
+    /// Reverses foreground and background colors
+    Reverse,
+
+    /// Hidden text (foreground matches background)
+    Hidden,
+}
@@ -0,0 +1,127 @@
+//! Conversions between sRGB and the CIE Lch(ab) color space.
+//!
+//! Lch separates lightness, chroma (saturation) and hue into independent
+//! axes, which makes it a much more natural space than RGB to lighten,
+//! darken, saturate or rotate the hue of a color.
+
+// D65 reference white, as used by sRGB.
+const XN: f32 = 0.950_47;
+const YN: f32 = 1.0;
+const ZN: f32 = 1.088_83;
+
+fn srgb_to_linear(c: f32) -> f32 {
+    if c <= 0.040_45 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(c: f32) -> f32 {
+    if c <= 0.003_130_8 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+fn lab_f(t: f32) -> f32 {
+    const DELTA: f32 = 6.0 / 29.0;
+    if t > DELTA.powi(3) {
+        t.cbrt()
+    } else {
+        t / (3.0 * DELTA * DELTA) + 4.0 / 29.0
+    }
+}
+
+fn lab_f_inv(t: f32) -> f32 {
+    const DELTA: f32 = 6.0 / 29.0;
+    if t > DELTA {
+        t.powi(3)
+    } else {
+        3.0 * DELTA * DELTA * (t - 4.0 / 29.0)
+    }
+}
+
+/// Converts an 8-bit sRGB triplet into CIE Lch (L, C, H in degrees).
+pub fn rgb_to_lch(r: u8, g: u8, b: u8) -> (f32, f32, f32) {
+    let r = srgb_to_linear(r as f32 / 255.0);
+    let g = srgb_to_linear(g as f32 / 255.0);
+    let b = srgb_to_linear(b as f32 / 255.0);
+
+    let x = 0.412_456_4 * r + 0.357_576_1 * g + 0.180_437_5 * b;
+    let y = 0.212_672_9 * r + 0.715_152_2 * g + 0.072_175_0 * b;
+    let z = 0.019_333_9 * r + 0.119_192 * g + 0.950_304_1 * b;
+
+    let fx = lab_f(x / XN);
+    let fy = lab_f(y / YN);
+    let fz = lab_f(z / ZN);
+
+    let l = 116.0 * fy - 16.0;
+    let a = 500.0 * (fx - fy);
+    let b = 200.0 * (fy - fz);
+
+    let c = a.hypot(b);
+    let h = b.atan2(a).to_degrees().rem_euclid(360.0);
+
+    (l, c, h)
+}
+
+/// Converts CIE Lch (L, C, H in degrees) back to an 8-bit sRGB triplet.
+///
+/// Out-of-gamut results are clamped to the valid `[0, 255]` range.
+pub fn lch_to_rgb(l: f32, c: f32, h: f32) -> (u8, u8, u8) {
+    let h = h.to_radians();
+    let a = c * h.cos();
+    let b = c * h.sin();
+
+    let fy = (l + 16.0) / 116.0;
+    let fx = fy + a / 500.0;
+    let fz = fy - b / 200.0;
+
+    let x = XN * lab_f_inv(fx);
+    let y = YN * lab_f_inv(fy);
+    let z = ZN * lab_f_inv(fz);
+
+    let r = 3.240_454_2 * x - 1.537_138_5 * y - 0.498_531_4 * z;
+    let g = -0.969_266 * x + 1.876_010_8 * y + 0.041_556_0 * z;
+    let b = 0.055_643_4 * x - 0.204_025_9 * y + 1.057_225_2 * z;
+
+    let to_u8 = |c: f32| (linear_to_srgb(c).clamp(0.0, 1.0) * 255.0).round() as u8;
+
+    (to_u8(r), to_u8(g), to_u8(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_roundtrips(r: u8, g: u8, b: u8) {
+        let (l, c, h) = rgb_to_lch(r, g, b);
+        let (r2, g2, b2) = lch_to_rgb(l, c, h);
+        assert_eq!((r, g, b), (r2, g2, b2), "Lch round-trip for #{:02x}{:02x}{:02x}", r, g, b);
+    }
+
+    #[test]
+    fn roundtrips_known_colors() {
+        assert_roundtrips(0, 0, 0);
+        assert_roundtrips(255, 255, 255);
+        assert_roundtrips(128, 128, 128);
+        assert_roundtrips(200, 60, 60);
+        assert_roundtrips(30, 144, 255);
+    }
+
+    #[test]
+    fn white_has_no_chroma() {
+        let (l, c, _) = rgb_to_lch(255, 255, 255);
+        assert!((l - 100.0).abs() < 0.5);
+        assert!(c < 0.5);
+    }
+
+    #[test]
+    fn black_has_zero_lightness() {
+        let (l, c, _) = rgb_to_lch(0, 0, 0);
+        assert!(l.abs() < 0.5);
+        assert!(c.abs() < 0.5);
+    }
+}
@@ -0,0 +1,43 @@
+//! Handle colors and themes in the UI.
+//!
+//! Rather than setting colors directly, views are generally meant to refer
+//! to abstract roles in a [`Palette`], which are then resolved to a concrete
+//! [`Color`] (and, through [`Style`], a set of [`Effect`]s) when printing.
+
+mod color;
+mod color_mode;
+mod color_style;
+mod effect;
+mod lch;
+mod palette;
+mod style;
+
+pub use self::color::{BaseColor, Color};
+pub use self::color_mode::ColorMode;
+pub use self::color_style::{ColorStyle, ColorType};
+pub use self::effect::Effect;
+pub use self::palette::{load_toml, Palette, PaletteColor, ThemeError};
+pub use self::style::Style;
+
+/// Combines a front and back color.
+///
+/// This is the result of resolving a [`ColorStyle`] against a [`Palette`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ColorPair {
+    /// Color used for the foreground.
+    pub front: Color,
+
+    /// Color used for the background.
+    pub back: Color,
+}
+
+impl ColorPair {
+    /// Return a new color pair with the front and back colors swapped.
+    #[must_use]
+    pub fn invert(self) -> Self {
+        ColorPair {
+            front: self.back,
+            back: self.front,
+        }
+    }
+}
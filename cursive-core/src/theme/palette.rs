@@ -0,0 +1,479 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+use enumset::EnumSet;
+use serde::de::{Deserializer, Error as _};
+use serde::Deserialize;
+
+use super::{BaseColor, Color, ColorMode, ColorStyle, ColorType, Effect, Style};
+
+/// Color entry in a palette.
+///
+/// Each role is assigned a concrete [`Color`] by the current [`Palette`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PaletteColor {
+    /// Color used for the application background.
+    Background,
+
+    /// Color used by view shadows.
+    Shadow,
+
+    /// Color used for a view background.
+    View,
+
+    /// Main text color.
+    Primary,
+
+    /// Secondary text color.
+    Secondary,
+
+    /// Tertiary text color.
+    Tertiary,
+
+    /// Primary color for a title.
+    TitlePrimary,
+
+    /// Alternative color for a title.
+    TitleSecondary,
+
+    /// Color for highlighted elements.
+    Highlight,
+
+    /// Color for highlighted elements (when the view is not in focus).
+    HighlightInactive,
+
+    /// Color for text on a highlighted element.
+    HighlightText,
+}
+
+impl PaletteColor {
+    /// All the existing palette colors.
+    pub const ALL: [PaletteColor; 11] = [
+        PaletteColor::Background,
+        PaletteColor::Shadow,
+        PaletteColor::View,
+        PaletteColor::Primary,
+        PaletteColor::Secondary,
+        PaletteColor::Tertiary,
+        PaletteColor::TitlePrimary,
+        PaletteColor::TitleSecondary,
+        PaletteColor::Highlight,
+        PaletteColor::HighlightInactive,
+        PaletteColor::HighlightText,
+    ];
+
+    /// Returns the color currently assigned to this palette color.
+    pub fn resolve(self, palette: &Palette) -> Color {
+        palette[self]
+    }
+}
+
+impl FromStr for PaletteColor {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized = s.to_ascii_lowercase().replace(['-', '_'], "");
+
+        Ok(match normalized.as_str() {
+            "background" => PaletteColor::Background,
+            "shadow" => PaletteColor::Shadow,
+            "view" => PaletteColor::View,
+            "primary" => PaletteColor::Primary,
+            "secondary" => PaletteColor::Secondary,
+            "tertiary" => PaletteColor::Tertiary,
+            "titleprimary" => PaletteColor::TitlePrimary,
+            "titlesecondary" => PaletteColor::TitleSecondary,
+            "highlight" => PaletteColor::Highlight,
+            "highlightinactive" => PaletteColor::HighlightInactive,
+            "highlighttext" => PaletteColor::HighlightText,
+            _ => return Err(()),
+        })
+    }
+}
+
+/// Color configuration for the application.
+///
+/// Assign each [`PaletteColor`] a concrete [`Color`], plus an optional set of
+/// text [`Effect`]s (bold, underline, ...) stored for that role and returned
+/// by [`Palette::effects`]. Resolving a [`ColorStyle`] or [`Style`] does not
+/// add these effects.
+#[derive(Clone, Debug)]
+pub struct Palette {
+    basic: HashMap<PaletteColor, Color>,
+    effects: HashMap<PaletteColor, EnumSet<Effect>>,
+    monochrome: bool,
+}
+
+impl Default for Palette {
+    /// Returns the default palette for this library.
+    fn default() -> Self {
+        let mut basic = HashMap::new();
+
+        basic.insert(PaletteColor::Background, Color::Dark(BaseColor::Blue));
+        basic.insert(PaletteColor::Shadow, Color::Dark(BaseColor::Black));
+        basic.insert(PaletteColor::View, Color::Dark(BaseColor::White));
+        basic.insert(PaletteColor::Primary, Color::Dark(BaseColor::Black));
+        basic.insert(PaletteColor::Secondary, Color::Dark(BaseColor::Blue));
+        basic.insert(PaletteColor::Tertiary, Color::Light(BaseColor::White));
+        basic.insert(PaletteColor::TitlePrimary, Color::Dark(BaseColor::Red));
+        basic.insert(PaletteColor::TitleSecondary, Color::Dark(BaseColor::Yellow));
+        basic.insert(PaletteColor::Highlight, Color::Dark(BaseColor::Red));
+        basic.insert(PaletteColor::HighlightInactive, Color::Dark(BaseColor::Blue));
+        basic.insert(PaletteColor::HighlightText, Color::Dark(BaseColor::White));
+
+        Palette {
+            basic,
+            effects: HashMap::new(),
+            monochrome: false,
+        }
+    }
+}
+
+impl Palette {
+    /// Sets the color for the given role.
+    pub fn set_color(&mut self, role: PaletteColor, color: Color) {
+        self.basic.insert(role, color);
+    }
+
+    /// Returns the effects associated with the given role, if any.
+    pub fn effects(&self, role: PaletteColor) -> EnumSet<Effect> {
+        self.effects.get(&role).copied().unwrap_or_default()
+    }
+
+    /// Sets the effects associated with the given role.
+    pub fn set_effects(&mut self, role: PaletteColor, effects: EnumSet<Effect>) {
+        self.effects.insert(role, effects);
+    }
+
+    /// Returns whether this palette is currently restricted to the
+    /// terminal's default color (see [`Palette::set_color_mode`]).
+    pub fn is_monochrome(&self) -> bool {
+        self.monochrome
+    }
+
+    /// Derives a full palette from a single seed color.
+    ///
+    /// `primary` is used as-is for [`PaletteColor::Primary`]; every other
+    /// role listed below is obtained by lightening, darkening, desaturating
+    /// or hue-shifting the seed, so that a coherent theme can be generated
+    /// from one input color:
+    ///
+    /// * [`PaletteColor::Background`]: heavily darkened and desaturated.
+    /// * [`PaletteColor::View`]: darkened and desaturated.
+    /// * [`PaletteColor::Secondary`]: slightly darkened.
+    /// * [`PaletteColor::Tertiary`]: lightened and slightly desaturated.
+    /// * [`PaletteColor::Highlight`]: hue-shifted by 180° (the complement).
+    /// * [`PaletteColor::HighlightInactive`]: the highlight, darkened and desaturated.
+    ///
+    /// Roles not listed above (e.g. [`PaletteColor::Shadow`]) keep their
+    /// default value.
+    pub fn from_seed(primary: Color) -> Palette {
+        let mut palette = Palette::default();
+
+        let background = primary.darken(0.35).desaturate(0.4);
+        let view = primary.darken(0.15).desaturate(0.2);
+        let secondary = primary.darken(0.1);
+        let tertiary = primary.lighten(0.2).desaturate(0.1);
+        let highlight = primary.shift_hue(180.0);
+        let highlight_inactive = highlight.darken(0.2).desaturate(0.3);
+
+        palette.set_color(PaletteColor::Primary, primary);
+        palette.set_color(PaletteColor::Secondary, secondary);
+        palette.set_color(PaletteColor::Tertiary, tertiary);
+        palette.set_color(PaletteColor::Highlight, highlight);
+        palette.set_color(PaletteColor::HighlightInactive, highlight_inactive);
+        palette.set_color(PaletteColor::View, view);
+        palette.set_color(PaletteColor::Background, background);
+
+        palette
+    }
+
+    /// Applies a [`ColorMode`], given whether the backend's output is an
+    /// interactive terminal.
+    ///
+    /// Once applied, every color resolved through [`ColorType::resolve`]
+    /// (and so [`ColorStyle::resolve`] and [`Style::resolve`]) against this
+    /// palette, whether from a [`PaletteColor`] role or a direct [`Color`],
+    /// collapses to [`Color::TerminalDefault`] until the mode is changed
+    /// again. [`PaletteColor::resolve`] and indexing still return the color
+    /// assigned to the role.
+    pub fn set_color_mode(&mut self, mode: ColorMode, is_tty: bool) {
+        self.monochrome = !mode.effective(is_tty);
+    }
+}
+
+/// Error returned when a theme fails to load.
+#[derive(Debug)]
+pub enum ThemeError {
+    /// The TOML content could not be parsed.
+    Toml(toml::de::Error),
+}
+
+impl fmt::Display for ThemeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ThemeError::Toml(err) => write!(f, "invalid theme: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for ThemeError {}
+
+impl From<toml::de::Error> for ThemeError {
+    fn from(err: toml::de::Error) -> Self {
+        ThemeError::Toml(err)
+    }
+}
+
+/// Loads a [`Palette`] from a TOML-formatted theme description.
+///
+/// Each key is a palette role name (e.g. `primary`, `title_secondary`), and
+/// each value is either a plain color string, or a table of the form
+/// `{ fg = "...", bg = "...", bold = true, underline = true, invert = true }`.
+/// `fg`/`bg` accept anything [`ColorType`] does: a literal color (see
+/// [`Color::parse`]) or another role name (e.g. `fg = "primary"`); such
+/// references are resolved against the roles defined by literal colors in
+/// this same theme, regardless of the order the roles appear in. Since a
+/// role only stores a single color, `bg` is only meaningful alongside
+/// `invert = true` (which swaps it into `fg`'s place); setting one without
+/// the other is rejected. Roles left unspecified keep their default value.
+pub fn load_toml(content: &str) -> Result<Palette, ThemeError> {
+    toml::from_str(content).map_err(ThemeError::from)
+}
+
+/// Raw shape of a single palette entry, as found in a theme file.
+#[derive(Clone, Deserialize)]
+#[serde(untagged)]
+enum RawPaletteEntry {
+    Color(String),
+    Style {
+        fg: Option<String>,
+        bg: Option<String>,
+        #[serde(default)]
+        bold: bool,
+        #[serde(default)]
+        italic: bool,
+        #[serde(default)]
+        underline: bool,
+        #[serde(default)]
+        strikethrough: bool,
+        #[serde(default)]
+        dim: bool,
+        #[serde(default)]
+        blink: bool,
+        #[serde(default)]
+        reverse: bool,
+        #[serde(default)]
+        hidden: bool,
+        #[serde(default)]
+        invert: bool,
+    },
+}
+
+impl RawPaletteEntry {
+    /// Whether this entry's `fg`/`bg` (or its plain color string) names
+    /// another palette role rather than only literal colors.
+    ///
+    /// Used by [`Palette`]'s `Deserialize` impl to resolve literal colors
+    /// first, independently of iteration order, before resolving
+    /// role-referencing entries against the result.
+    fn references_role(&self) -> bool {
+        let is_reference = |value: &str| Color::parse(value).is_none();
+
+        match self {
+            RawPaletteEntry::Color(value) => is_reference(value),
+            RawPaletteEntry::Style { fg, bg, .. } => {
+                fg.as_deref().is_some_and(is_reference) || bg.as_deref().is_some_and(is_reference)
+            }
+        }
+    }
+
+    /// Resolves this entry to a concrete color and effect set.
+    ///
+    /// `palette` is the palette built so far, used to resolve `fg`/`bg`
+    /// values that name another role (e.g. `fg = "primary"`) rather than a
+    /// literal color.
+    fn into_color_and_effects<E: serde::de::Error>(self, palette: &Palette) -> Result<(Color, EnumSet<Effect>), E> {
+        let parse_color = |value: &str| -> Result<Color, E> {
+            ColorType::from_str(value)
+                .map_err(|_| E::custom(format!("invalid color or palette role `{}`", value)))
+                .map(|color_type| color_type.resolve(palette, Color::TerminalDefault))
+        };
+
+        match self {
+            RawPaletteEntry::Color(value) => Ok((parse_color(&value)?, EnumSet::new())),
+            RawPaletteEntry::Style {
+                fg,
+                bg,
+                bold,
+                italic,
+                underline,
+                strikethrough,
+                dim,
+                blink,
+                reverse,
+                hidden,
+                invert,
+            } => {
+                // A palette role only stores a single color, so `bg` only
+                // makes sense when `invert` swaps it into `fg`'s place;
+                // otherwise it would be silently dropped. Symmetrically,
+                // `invert` without a `bg` would silently discard `fg`
+                // instead, so both combinations are rejected.
+                if bg.is_some() && !invert {
+                    return Err(E::custom(
+                        "`bg` has no effect on a palette role unless `invert = true` is also set",
+                    ));
+                }
+                if invert && bg.is_none() {
+                    return Err(E::custom(
+                        "`invert` has nothing to swap `fg` with unless `bg` is also set",
+                    ));
+                }
+
+                let fg = fg
+                    .as_deref()
+                    .map(parse_color)
+                    .transpose()?
+                    .unwrap_or(Color::TerminalDefault);
+                let bg = bg
+                    .as_deref()
+                    .map(parse_color)
+                    .transpose()?
+                    .unwrap_or(Color::TerminalDefault);
+
+                let mut style = Style::from(ColorStyle::new(fg, bg));
+                if bold {
+                    style = style.bold();
+                }
+                if italic {
+                    style = style.italic();
+                }
+                if underline {
+                    style = style.underline();
+                }
+                if strikethrough {
+                    style = style.strikethrough();
+                }
+                if dim {
+                    style = style.dim();
+                }
+                if blink {
+                    style = style.blink();
+                }
+                if reverse {
+                    style = style.reverse();
+                }
+                if hidden {
+                    style = style.hidden();
+                }
+                if invert {
+                    style.color = style.color.invert();
+                }
+
+                let front = match style.color.front {
+                    ColorType::Color(color) => color,
+                    _ => Color::TerminalDefault,
+                };
+
+                Ok((front, style.effects))
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Palette {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw: HashMap<String, RawPaletteEntry> = HashMap::deserialize(deserializer)?;
+
+        // `raw` iterates in randomized HashMap order, but entries that
+        // reference another role (e.g. `fg = "primary"`) need that role
+        // already resolved. Sort for a deterministic order, then resolve in
+        // two passes: literal colors first (order-independent), then
+        // role-referencing entries against the now fully-populated palette.
+        let mut entries: Vec<(String, RawPaletteEntry)> = raw.into_iter().collect();
+        entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        let mut palette = Palette::default();
+        for (name, entry) in entries.iter().filter(|(_, entry)| !entry.references_role()) {
+            let role = PaletteColor::from_str(name)
+                .map_err(|_| D::Error::custom(format!("unknown palette role `{}`", name)))?;
+            let (color, effects) = entry.clone().into_color_and_effects::<D::Error>(&palette)?;
+
+            palette.set_color(role, color);
+            palette.set_effects(role, effects);
+        }
+        for (name, entry) in entries.iter().filter(|(_, entry)| entry.references_role()) {
+            let role = PaletteColor::from_str(name)
+                .map_err(|_| D::Error::custom(format!("unknown palette role `{}`", name)))?;
+            let (color, effects) = entry.clone().into_color_and_effects::<D::Error>(&palette)?;
+
+            palette.set_color(role, color);
+            palette.set_effects(role, effects);
+        }
+
+        Ok(palette)
+    }
+}
+
+impl std::ops::Index<PaletteColor> for Palette {
+    type Output = Color;
+
+    fn index(&self, palette_color: PaletteColor) -> &Color {
+        &self.basic[&palette_color]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn loads_literal_color_role() {
+        let palette = load_toml(r##"primary = "#ff0000""##).unwrap();
+        assert_eq!(palette[PaletteColor::Primary], Color::Rgb(255, 0, 0));
+    }
+
+    #[test]
+    fn loads_style_table_with_invert() {
+        let palette = load_toml(
+            r##"
+            secondary = { fg = "#00ff00", bg = "#0000ff", bold = true, invert = true }
+            "##,
+        )
+        .unwrap();
+
+        // `invert` swaps fg/bg, so the role ends up storing the bg color.
+        assert_eq!(palette[PaletteColor::Secondary], Color::Rgb(0, 0, 255));
+        assert_eq!(palette.effects(PaletteColor::Secondary), EnumSet::only(Effect::Bold));
+    }
+
+    #[test]
+    fn resolves_cross_role_reference_deterministically() {
+        let toml = r##"
+            primary = "#ff0000"
+            secondary = { fg = "primary" }
+        "##;
+
+        // Regression test: this used to depend on HashMap iteration order,
+        // so run it enough times to catch a reintroduced race.
+        for _ in 0..50 {
+            let palette = load_toml(toml).unwrap();
+            assert_eq!(palette[PaletteColor::Secondary], Color::Rgb(255, 0, 0));
+        }
+    }
+
+    #[test]
+    fn rejects_bg_without_invert() {
+        assert!(load_toml(r##"primary = { fg = "#ff0000", bg = "#00ff00" }"##).is_err());
+    }
+
+    #[test]
+    fn rejects_invert_without_bg() {
+        assert!(load_toml(r##"primary = { fg = "#ff0000", invert = true }"##).is_err());
+    }
+}
@@ -0,0 +1,148 @@
+use enumset::EnumSet;
+
+use super::{Color, ColorPair, ColorStyle, Effect, Palette};
+
+/// Combines a [`ColorStyle`] with a set of text [`Effect`]s.
+///
+/// This fully describes how a span of text should be rendered: which colors
+/// to use, and which attributes (bold, underline, ...) to apply.
+///
+/// The `Default` value uses the parent's colors and no effect, so merging a
+/// child `Style` into a parent one never discards information the child
+/// didn't specify.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub struct Style {
+    /// Color to apply, if any.
+    pub color: ColorStyle,
+
+    /// Effects to apply.
+    pub effects: EnumSet<Effect>,
+}
+
+impl Style {
+    /// Merge style `b` over style `a`.
+    ///
+    /// This merges the colors (see [`ColorStyle::merge`]) and unions the
+    /// effect sets, so a child style can add an effect without losing the
+    /// parent's colors (or the other way around).
+    pub fn merge(a: Self, b: Self) -> Self {
+        Style {
+            color: ColorStyle::merge(a.color, b.color),
+            effects: a.effects | b.effects,
+        }
+    }
+
+    /// Resolves this style against a palette and the previously resolved color.
+    ///
+    /// Returns the resolved [`ColorPair`] along with the set of effects to apply.
+    pub fn resolve(&self, palette: &Palette, previous: ColorPair) -> (ColorPair, EnumSet<Effect>) {
+        (self.color.resolve(palette, previous), self.effects)
+    }
+
+    #[must_use]
+    fn with_effect(mut self, effect: Effect) -> Self {
+        self.effects.insert(effect);
+        self
+    }
+
+    /// Adds the bold effect to this style.
+    #[must_use]
+    pub fn bold(self) -> Self {
+        self.with_effect(Effect::Bold)
+    }
+
+    /// Adds the italic effect to this style.
+    #[must_use]
+    pub fn italic(self) -> Self {
+        self.with_effect(Effect::Italic)
+    }
+
+    /// Adds the underline effect to this style.
+    #[must_use]
+    pub fn underline(self) -> Self {
+        self.with_effect(Effect::Underline)
+    }
+
+    /// Adds the strikethrough effect to this style.
+    #[must_use]
+    pub fn strikethrough(self) -> Self {
+        self.with_effect(Effect::Strikethrough)
+    }
+
+    /// Adds the dim effect to this style.
+    #[must_use]
+    pub fn dim(self) -> Self {
+        self.with_effect(Effect::Dim)
+    }
+
+    /// Adds the blink effect to this style.
+    #[must_use]
+    pub fn blink(self) -> Self {
+        self.with_effect(Effect::Blink)
+    }
+
+    /// Adds the reverse effect to this style.
+    #[must_use]
+    pub fn reverse(self) -> Self {
+        self.with_effect(Effect::Reverse)
+    }
+
+    /// Adds the hidden effect to this style.
+    #[must_use]
+    pub fn hidden(self) -> Self {
+        self.with_effect(Effect::Hidden)
+    }
+}
+
+impl From<ColorStyle> for Style {
+    fn from(color: ColorStyle) -> Self {
+        Style {
+            color,
+            effects: EnumSet::new(),
+        }
+    }
+}
+
+impl From<Color> for Style {
+    fn from(color: Color) -> Self {
+        ColorStyle::from(color).into()
+    }
+}
+
+impl From<Effect> for Style {
+    fn from(effect: Effect) -> Self {
+        Style {
+            color: ColorStyle::inherit_parent(),
+            effects: EnumSet::only(effect),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merge_unions_effects() {
+        let a = Style::from(Effect::Bold);
+        let b = Style::from(Effect::Underline);
+        assert_eq!(Style::merge(a, b).effects, Effect::Bold | Effect::Underline);
+    }
+
+    #[test]
+    fn merge_lets_b_color_override_a() {
+        let a = Style::from(Color::Rgb(255, 0, 0));
+        let b = Style::from(Color::Rgb(0, 0, 255));
+        assert_eq!(Style::merge(a, b).color, ColorStyle::front(Color::Rgb(0, 0, 255)));
+    }
+
+    #[test]
+    fn merge_keeps_a_color_when_b_only_adds_an_effect() {
+        let a = Style::from(Color::Rgb(255, 0, 0));
+        let b = Style::from(Effect::Bold);
+        let merged = Style::merge(a, b);
+
+        assert_eq!(merged.color, ColorStyle::front(Color::Rgb(255, 0, 0)));
+        assert_eq!(merged.effects, EnumSet::only(Effect::Bold));
+    }
+}